@@ -17,6 +17,10 @@
 //! per second. `StatusLine` decouples redrawing rate from the data update rate by using a
 //! background thread to handle text printing with low frequency.
 //!
+//! If you need more than one status line on screen at the same time, register them with a
+//! [`StatusBoard`] instead of creating standalone `StatusLine`s, so their redraws are
+//! coordinated into a single block instead of overwriting each other.
+//!
 //! ## Example
 //! ```rust
 //! use std::fmt::{Display, Formatter};
@@ -42,42 +46,206 @@
 //! ```
 //!
 
+use std::cell::RefCell;
 use std::fmt::Display;
 use std::io::Write;
 use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::thread;
 use std::time::Duration;
 
 use ansi_escapes::{CursorLeft, CursorPrevLine, EraseDown};
+use terminal_size::terminal_size;
+
+/// Computes the width of `s` as it would appear on screen, counting one column
+/// per character and ignoring embedded ANSI CSI escape sequences (`ESC [ ...`,
+/// e.g. color codes), which don't occupy any visible columns.
+fn display_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            if let Some('[') = chars.clone().next() {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
 
-fn redraw(ansi: bool, state: &impl Display) {
-    let stderr = std::io::stderr();
-    let mut stderr = stderr.lock();
-    let contents = format!("{}", state);
-    if ansi {
-        let line_count = contents.chars().filter(|c| *c == '\n').count();
-        write!(&mut stderr, "{}{}{}", EraseDown, contents, CursorLeft).unwrap();
+/// Truncates `line` so that its display width (ignoring ANSI escape
+/// sequences) doesn't exceed `max_width`, cutting on a character boundary.
+fn truncate_line(line: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    let mut chars = line.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c == '\u{1b}' {
+            if let Some((_, '[')) = chars.clone().next() {
+                chars.next();
+                for (_, c) in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+        if width == max_width {
+            return &line[..i];
+        }
+        width += 1;
+    }
+    line
+}
+
+/// Number of visual terminal rows a logical `line` occupies once the
+/// terminal soft-wraps it at `term_width` columns.
+fn visual_row_count(line: &str, term_width: usize) -> usize {
+    if term_width == 0 {
+        return 1;
+    }
+    let width = display_width(line);
+    1.max(width.div_ceil(term_width))
+}
+
+thread_local! {
+    /// Addresses of the `last_rendered` mutexes this thread currently holds through
+    /// [`lock_last_rendered`], e.g. for the duration of an in-progress
+    /// [`StatusLine::suspend`]. Used by [`lock_last_rendered`] to turn a
+    /// reentrant lock attempt (e.g. calling `refresh` or `suspend` again from within
+    /// `suspend`'s closure, on the same line or another line on the same board) into an
+    /// immediate panic instead of a silent deadlock on the non-reentrant `Mutex`.
+    static HELD_LOCKS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Releases the reentrancy bookkeeping done in [`lock_last_rendered`] once the
+/// corresponding `MutexGuard` is dropped.
+struct LockToken(usize);
+
+impl Drop for LockToken {
+    fn drop(&mut self) {
+        HELD_LOCKS.with(|held| {
+            let mut held = held.borrow_mut();
+            if let Some(pos) = held.iter().rposition(|&addr| addr == self.0) {
+                held.remove(pos);
+            }
+        });
+    }
+}
+
+/// Locks a `last_rendered` mutex, panicking instead of blocking if this thread already
+/// holds it (i.e. we're being called reentrantly from within [`StatusLine::suspend`]).
+/// Contention from a *different* thread is unaffected and blocks as normal.
+fn lock_last_rendered(mutex: &Mutex<String>) -> (MutexGuard<'_, String>, LockToken) {
+    let addr = mutex as *const Mutex<String> as usize;
+    let reentrant = HELD_LOCKS.with(|held| held.borrow().contains(&addr));
+    assert!(
+        !reentrant,
+        "StatusLine::suspend was re-entered from within its own closure (e.g. by calling \
+         refresh, println or suspend again on the same line or another line on its board); \
+         this would otherwise deadlock"
+    );
+    HELD_LOCKS.with(|held| held.borrow_mut().push(addr));
+    (mutex.lock().unwrap(), LockToken(addr))
+}
+
+fn redraw<D: Display>(options: &Options, state: &State<D>) {
+    let (mut last_rendered, _guard) = lock_last_rendered(&state.last_rendered);
+    redraw_locked(options, state, &mut last_rendered);
+}
+
+/// Does the actual work of `redraw`, assuming `last_rendered` is the locked
+/// contents of `state.last_rendered`. Split out so [`StatusLine::suspend`]
+/// can hold the lock across clearing, running the caller's closure, and
+/// redrawing, without re-entering the mutex.
+fn redraw_locked<D: Display>(options: &Options, state: &State<D>, last_rendered: &mut String) {
+    render_locked(options, format!("{}", state.data), last_rendered);
+}
+
+/// Repaints `contents`, assuming `last_rendered` is the locked contents of the
+/// previous frame. Shared by [`redraw_locked`], which formats a single entry's
+/// `Display`, and [`redraw_board`], which joins the text of several entries first.
+fn render_locked(options: &Options, contents: String, last_rendered: &mut String) {
+    let term_width = options
+        .enable_ansi_escapes
+        .then(|| terminal_size().map(|(w, _)| w.0 as usize))
+        .flatten();
+    render_with_term_width(options, contents, term_width, last_rendered);
+}
+
+/// Does the actual work of [`render_locked`], with the terminal width passed in rather
+/// than detected, so the truncation and redraw logic can be tested without a real terminal.
+fn render_with_term_width(
+    options: &Options,
+    contents: String,
+    term_width: Option<usize>,
+    last_rendered: &mut String,
+) {
+    // Truncate (if requested) before comparing against the cache, so the cache always
+    // holds exactly what was last written to the terminal, not the pre-truncation text.
+    let contents = if options.enable_ansi_escapes && options.truncate_to_terminal_width {
+        match term_width {
+            Some(term_width) => contents
+                .split('\n')
+                .map(|line| truncate_line(line, term_width))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            None => contents,
+        }
+    } else {
+        contents
+    };
+    if *last_rendered == contents {
+        return;
+    }
+    let mut output = options.output.lock();
+    if options.enable_ansi_escapes {
+        let line_count = contents
+            .split('\n')
+            .map(|line| visual_row_count(line, term_width.unwrap_or(usize::MAX)))
+            .sum::<usize>()
+            - 1;
+        write!(&mut output, "{}{}{}", EraseDown, contents, CursorLeft).unwrap();
         for _ in 0..line_count {
-            write!(&mut stderr, "{}", CursorPrevLine).unwrap();
+            write!(&mut output, "{}", CursorPrevLine).unwrap();
         }
     } else {
-        writeln!(&mut stderr, "{}", contents).unwrap();
+        writeln!(&mut output, "{}", contents).unwrap();
     }
+    *last_rendered = contents;
 }
 
-fn clear(ansi: bool) {
-    if ansi {
-        let stderr = std::io::stderr();
-        let mut stderr = stderr.lock();
-        write!(&mut stderr, "{}", EraseDown).unwrap();
+fn clear<D>(options: &Options, state: &State<D>) {
+    let (mut last_rendered, _guard) = lock_last_rendered(&state.last_rendered);
+    clear_locked(options, &mut last_rendered);
+}
+
+/// Does the actual work of `clear`, assuming `last_rendered` is the locked
+/// contents of `state.last_rendered`. See [`redraw_locked`].
+fn clear_locked(options: &Options, last_rendered: &mut String) {
+    if options.enable_ansi_escapes {
+        let mut output = options.output.lock();
+        write!(&mut output, "{}", EraseDown).unwrap();
     }
+    // Forget what was last rendered, so the status gets redrawn in full
+    // the next time it becomes visible, even if the data hasn't changed.
+    last_rendered.clear();
 }
 
 struct State<D> {
     data: D,
     visible: AtomicBool,
+    /// Caches the last text written to the terminal, so `redraw` can skip
+    /// the erase-and-write sequence when nothing actually changed.
+    last_rendered: Mutex<String>,
 }
 
 impl<D> State<D> {
@@ -85,11 +253,180 @@ impl<D> State<D> {
         State {
             data: inner,
             visible: AtomicBool::new(false),
+            last_rendered: Mutex::new(String::new()),
+        }
+    }
+}
+
+/// A single row tracked by a [`StatusBoard`].
+trait Entry: Send + Sync {
+    /// Returns the text to display for this entry, or `None` if it is currently hidden.
+    fn render(&self) -> Option<String>;
+}
+
+impl<D: Display + Send + Sync> Entry for State<D> {
+    fn render(&self) -> Option<String> {
+        self.visible
+            .load(Ordering::Acquire)
+            .then(|| format!("{}", self.data))
+    }
+}
+
+/// Shared state behind a [`StatusBoard`]: the registered entries, plus the options and
+/// the cache of the last combined frame used to redraw them as a single block.
+struct Board {
+    entries: Mutex<Vec<Arc<dyn Entry>>>,
+    options: Options,
+    last_rendered: Mutex<String>,
+}
+
+impl Board {
+    /// Joins the text of every currently visible entry into a single block,
+    /// in the order they were added.
+    fn render(&self) -> String {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|entry| entry.render())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn redraw_board(board: &Board) {
+    let (mut last_rendered, _guard) = lock_last_rendered(&board.last_rendered);
+    render_locked(&board.options, board.render(), &mut last_rendered);
+}
+
+fn clear_board(board: &Board) {
+    let (mut last_rendered, _guard) = lock_last_rendered(&board.last_rendered);
+    clear_locked(&board.options, &mut last_rendered);
+}
+
+/// Coordinates several [`StatusLine`]s so they share one block of terminal rows instead
+/// of independently erasing and repainting over each other.
+///
+/// Each line added with [`StatusBoard::add`] renders its own `Display` independently, but
+/// the board joins them into a single combined frame and performs one erase-and-repaint
+/// pass for all of them from a single background thread.
+pub struct StatusBoard {
+    board: Arc<Board>,
+}
+
+impl StatusBoard {
+    /// Creates a new, empty `StatusBoard` with default options.
+    pub fn new() -> StatusBoard {
+        Self::with_options(Default::default())
+    }
+
+    /// Creates a new, empty `StatusBoard` with custom options.
+    pub fn with_options(options: Options) -> StatusBoard {
+        let board = Arc::new(Board {
+            entries: Mutex::new(Vec::new()),
+            options,
+            last_rendered: Mutex::new(String::new()),
+        });
+        let board_ref = board.clone();
+        thread::spawn(move || {
+            while Arc::strong_count(&board_ref) > 1 {
+                redraw_board(&board_ref);
+                thread::sleep(board_ref.options.refresh_period);
+            }
+        });
+        StatusBoard { board }
+    }
+
+    /// Registers a new status line on this board and returns a handle to it.
+    ///
+    /// The returned `StatusLine` is used exactly like a standalone one, except its
+    /// redraws are coordinated with the other lines already on the board.
+    pub fn add<D: Display + Send + Sync + 'static>(&self, data: D) -> StatusLine<D> {
+        let state = Arc::new(State::new(data));
+        state
+            .visible
+            .store(self.board.options.initially_visible, Ordering::Release);
+        let entry = state.clone() as Arc<dyn Entry>;
+        self.board.entries.lock().unwrap().push(entry.clone());
+        StatusLine {
+            state,
+            target: RedrawTarget::Board(self.board.clone(), entry),
+        }
+    }
+}
+
+impl Default for StatusBoard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for StatusBoard {
+    fn drop(&mut self) {
+        // Lines registered with `add` keep their own `Arc<Board>` clone alive, so the
+        // background thread may still be running and repainting after this handle is
+        // gone. Only clear the screen here if no lines are left to do it themselves;
+        // otherwise the last `StatusLine::drop` already leaves the board in the right
+        // state, and clearing now would just flash the display before the thread's
+        // next redraw brings it back. Also skip it if nothing was ever rendered (e.g.
+        // no lines were ever added), matching how standalone `StatusLine::drop` only
+        // clears when it was actually visible, instead of writing a no-op `EraseDown`.
+        let nothing_rendered = self.board.last_rendered.lock().unwrap().is_empty();
+        if self.board.entries.lock().unwrap().is_empty() && !nothing_rendered {
+            clear_board(&self.board);
         }
     }
 }
 
+/// Where the status line gets printed.
+///
+/// Defaults to [`Output::Stderr`], matching the historical behavior of this crate.
+#[derive(Clone)]
+pub enum Output {
+    /// Print to the standard error stream.
+    Stderr,
+    /// Print to the standard output stream.
+    Stdout,
+    /// Print to an arbitrary writer, e.g. an in-memory buffer in tests, or a specific tty handle.
+    Writer(Arc<Mutex<dyn Write + Send>>),
+}
+
+impl Output {
+    fn is_tty(&self) -> bool {
+        match self {
+            Output::Stderr => atty::is(atty::Stream::Stderr),
+            Output::Stdout => atty::is(atty::Stream::Stdout),
+            Output::Writer(_) => false,
+        }
+    }
+
+    /// Locks the underlying stream for the duration of the returned handle,
+    /// so a whole sequence of writes happens without another writer interleaving.
+    fn lock(&self) -> Box<dyn Write + '_> {
+        match self {
+            Output::Stderr => Box::new(std::io::stderr().lock()),
+            Output::Stdout => Box::new(std::io::stdout().lock()),
+            Output::Writer(w) => Box::new(MutexGuardWriter(w.lock().unwrap())),
+        }
+    }
+}
+
+/// Adapts a `MutexGuard` over a type-erased writer to `Write`, so it can be stored
+/// in a `Box<dyn Write>` alongside locked standard stream handles.
+struct MutexGuardWriter<'a>(std::sync::MutexGuard<'a, dyn Write + Send + 'static>);
+
+impl Write for MutexGuardWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.flush()
+    }
+}
+
 /// Options controlling how to display the status line
+#[derive(Clone)]
 pub struct Options {
     /// How long to wait between subsequent refreshes of the status.
     /// Defaults to 100 ms on interactive terminals (TTYs) and 1 s if the standard error
@@ -102,28 +439,84 @@ pub struct Options {
     pub initially_visible: bool,
 
     /// Set to true to enable ANSI escape codes.
-    /// By default set to true if the standard error is a TTY.
+    /// By default set to true if the chosen `output` is a TTY, unless overridden by the
+    /// `NO_COLOR`, `CLICOLOR` or `CLICOLOR_FORCE` environment variables: `NO_COLOR` (if
+    /// non-empty) or `CLICOLOR=0` force it off, `CLICOLOR_FORCE` (if not `0`) forces it on
+    /// otherwise, and TTY detection decides when none of them are set.
     /// If ANSI escape codes are disabled, the status line is not erased before each refresh,
     /// it is printed in a new line instead.
     pub enable_ansi_escapes: bool,
+
+    /// Set to true to clamp each line of the status to the width of the terminal,
+    /// truncating it on a character boundary instead of letting it soft-wrap.
+    /// Has no effect if the terminal width cannot be determined, or if
+    /// `enable_ansi_escapes` is false.
+    /// Defaults to false.
+    pub truncate_to_terminal_width: bool,
+
+    /// Where the status line gets printed. Defaults to [`Output::Stderr`].
+    pub output: Output,
 }
 
-impl Default for Options {
-    fn default() -> Self {
-        let is_tty = atty::is(atty::Stream::Stderr);
+impl Options {
+    /// Creates `Options` with autodetected `refresh_period` and `enable_ansi_escapes`
+    /// for printing to `output`, e.g. use `Options::for_output(Output::Stdout)` to get
+    /// the same autodetection as the default options, but targeting stdout instead.
+    pub fn for_output(output: Output) -> Options {
+        let is_tty = output.is_tty();
         let refresh_period_ms = if is_tty { 100 } else { 1000 };
         Options {
             refresh_period: Duration::from_millis(refresh_period_ms),
             initially_visible: true,
-            enable_ansi_escapes: is_tty,
+            enable_ansi_escapes: ansi_enabled(is_tty),
+            truncate_to_terminal_width: false,
+            output,
         }
     }
 }
 
+/// Decides whether ANSI escapes should be used by default, honoring the
+/// conventional `NO_COLOR`, `CLICOLOR` and `CLICOLOR_FORCE` environment
+/// variables on top of whether `is_tty` indicates an interactive terminal.
+fn ansi_enabled(is_tty: bool) -> bool {
+    ansi_enabled_with(is_tty, |key| std::env::var(key).ok())
+}
+
+/// Does the actual work of [`ansi_enabled`], with environment variable lookup passed in
+/// rather than read from the process directly, so the precedence rules can be tested
+/// without mutating real environment variables.
+fn ansi_enabled_with(is_tty: bool, env: impl Fn(&str) -> Option<String>) -> bool {
+    let no_color = env("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let clicolor_off = env("CLICOLOR").is_some_and(|v| v == "0");
+    if no_color || clicolor_off {
+        return false;
+    }
+    let clicolor_force = env("CLICOLOR_FORCE").is_some_and(|v| v != "0");
+    if clicolor_force {
+        return true;
+    }
+    is_tty
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self::for_output(Output::Stderr)
+    }
+}
+
+/// Where a [`StatusLine`] sends its redraws: either its own independent background
+/// thread, or a [`StatusBoard`] that coordinates it together with other lines.
+enum RedrawTarget {
+    Standalone(Options),
+    /// The board this line was registered on, plus the type-erased handle under which
+    /// it is registered in `board.entries` (so it can remove itself again on drop).
+    Board(Arc<Board>, Arc<dyn Entry>),
+}
+
 /// Wraps arbitrary data and displays it periodically on the screen.
 pub struct StatusLine<D: Display> {
     state: Arc<State<D>>,
-    options: Options,
+    target: RedrawTarget,
 }
 
 impl<D: Display + Send + Sync + 'static> StatusLine<D> {
@@ -139,15 +532,19 @@ impl<D: Display + Send + Sync + 'static> StatusLine<D> {
             .visible
             .store(options.initially_visible, Ordering::Release);
         let state_ref = state.clone();
+        let thread_options = options.clone();
         thread::spawn(move || {
             while Arc::strong_count(&state_ref) > 1 {
                 if state_ref.visible.load(Ordering::Acquire) {
-                    redraw(options.enable_ansi_escapes, &state_ref.data);
+                    redraw(&thread_options, &state_ref);
                 }
-                thread::sleep(options.refresh_period);
+                thread::sleep(thread_options.refresh_period);
             }
         });
-        StatusLine { state, options }
+        StatusLine {
+            state,
+            target: RedrawTarget::Standalone(options),
+        }
     }
 }
 
@@ -155,16 +552,27 @@ impl<D: Display> StatusLine<D> {
     /// Forces redrawing the status information immediately,
     /// without waiting for the next refresh cycle of the background refresh loop.
     pub fn refresh(&self) {
-        redraw(self.options.enable_ansi_escapes, &self.state.data);
+        match &self.target {
+            RedrawTarget::Standalone(options) => redraw(options, &self.state),
+            RedrawTarget::Board(board, _) => redraw_board(board),
+        }
     }
 
     /// Sets the visibility of the status line.
     pub fn set_visible(&self, visible: bool) {
         let was_visible = self.state.visible.swap(visible, Ordering::Release);
-        if !visible && was_visible {
-            clear(self.options.enable_ansi_escapes)
-        } else if visible && !was_visible {
-            redraw(self.options.enable_ansi_escapes, &self.state.data)
+        if was_visible == visible {
+            return;
+        }
+        match &self.target {
+            RedrawTarget::Standalone(options) => {
+                if visible {
+                    redraw(options, &self.state)
+                } else {
+                    clear(options, &self.state)
+                }
+            }
+            RedrawTarget::Board(board, _) => redraw_board(board),
         }
     }
 
@@ -172,6 +580,52 @@ impl<D: Display> StatusLine<D> {
     pub fn is_visible(&self) -> bool {
         self.state.visible.load(Ordering::Acquire)
     }
+
+    /// Temporarily hides the status line, runs `f`, and then redraws the status again.
+    ///
+    /// Use this to print ordinary log output above a live status line without the two
+    /// colliding: the status is cleared before `f` runs and redrawn right after, and the
+    /// background refresh thread is blocked from repainting for the duration of `f`, so
+    /// whatever `f` writes lands cleanly in the scrollback. If this line is on a
+    /// [`StatusBoard`], the whole board is suspended, not just this line.
+    ///
+    /// `f` must not call `refresh`, `println` or `suspend` again on this same status line
+    /// (directly or through another line sharing its board): the redraw lock is held for
+    /// the duration of `f`, and re-entering it would deadlock on the underlying, non-reentrant
+    /// mutex. This is detected and turned into a panic instead of a silent hang.
+    pub fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        match &self.target {
+            RedrawTarget::Standalone(options) => {
+                let (mut last_rendered, _guard) = lock_last_rendered(&self.state.last_rendered);
+                if self.is_visible() {
+                    clear_locked(options, &mut last_rendered);
+                }
+                let result = f();
+                if self.is_visible() {
+                    redraw_locked(options, &self.state, &mut last_rendered);
+                }
+                result
+            }
+            RedrawTarget::Board(board, _) => {
+                let (mut last_rendered, _guard) = lock_last_rendered(&board.last_rendered);
+                clear_locked(&board.options, &mut last_rendered);
+                let result = f();
+                render_locked(&board.options, board.render(), &mut last_rendered);
+                result
+            }
+        }
+    }
+
+    /// Prints `msg` on its own line above the status line.
+    ///
+    /// This is a convenience wrapper around [`suspend`](Self::suspend) for the common case
+    /// of interleaving a single log line with a live status. Note that `msg` always goes
+    /// through the real `println!` macro, i.e. the process's actual stdout — it does not
+    /// go through this status line's configured [`Options::output`]. Use `suspend` directly
+    /// if you need log output to land on the same sink as the status itself.
+    pub fn println(&self, msg: impl Display) {
+        self.suspend(|| println!("{}", msg));
+    }
 }
 
 impl<D: Display> Deref for StatusLine<D> {
@@ -183,8 +637,270 @@ impl<D: Display> Deref for StatusLine<D> {
 
 impl<D: Display> Drop for StatusLine<D> {
     fn drop(&mut self) {
-        if self.is_visible() {
-            clear(self.options.enable_ansi_escapes)
+        match &self.target {
+            RedrawTarget::Standalone(options) => {
+                if self.is_visible() {
+                    clear(options, &self.state)
+                }
+            }
+            RedrawTarget::Board(board, entry) => {
+                board
+                    .entries
+                    .lock()
+                    .unwrap()
+                    .retain(|e| !Arc::ptr_eq(e, entry));
+                redraw_board(board);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn captured_output() -> (Output, Arc<Mutex<Vec<u8>>>) {
+        let buf = Arc::new(Mutex::new(Vec::new()));
+        (Output::Writer(buf.clone()), buf)
+    }
+
+    #[test]
+    fn display_width_ignores_ansi_escapes() {
+        assert_eq!(display_width("\u{1b}[31mred\u{1b}[0m"), 3);
+        assert_eq!(display_width("plain"), 5);
+    }
+
+    #[test]
+    fn truncate_line_cuts_on_character_boundary_and_keeps_escapes() {
+        let colored = "\u{1b}[31mhello world\u{1b}[0m";
+        let truncated = truncate_line(colored, 5);
+        assert_eq!(display_width(truncated), 5);
+        assert!(truncated.starts_with("\u{1b}[31m"));
+        assert_eq!(truncate_line("short", 10), "short");
+    }
+
+    #[test]
+    fn visual_row_count_wraps_at_terminal_width() {
+        assert_eq!(visual_row_count("", 10), 1);
+        assert_eq!(visual_row_count("0123456789", 10), 1);
+        assert_eq!(visual_row_count("01234567890", 10), 2);
+        assert_eq!(visual_row_count("anything", 0), 1);
+    }
+
+    #[test]
+    fn ansi_enabled_respects_env_var_precedence() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |key: &str| {
+                vars.iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
+
+        assert!(ansi_enabled_with(true, env(&[])));
+        assert!(!ansi_enabled_with(false, env(&[])));
+        assert!(!ansi_enabled_with(true, env(&[("NO_COLOR", "1")])));
+        assert!(!ansi_enabled_with(true, env(&[("CLICOLOR", "0")])));
+        assert!(ansi_enabled_with(false, env(&[("CLICOLOR_FORCE", "1")])));
+        // NO_COLOR wins even when CLICOLOR_FORCE also asks for color.
+        assert!(!ansi_enabled_with(
+            true,
+            env(&[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")])
+        ));
+    }
+
+    #[test]
+    fn render_with_term_width_skips_redraw_when_unchanged_after_truncation() {
+        let (output, buf) = captured_output();
+        let options = Options {
+            enable_ansi_escapes: true,
+            truncate_to_terminal_width: true,
+            output,
+            ..Default::default()
+        };
+        let mut last_rendered = String::new();
+        let long_line = "a very long line of status text".to_string();
+
+        render_with_term_width(&options, long_line.clone(), Some(10), &mut last_rendered);
+        assert!(!buf.lock().unwrap().is_empty());
+
+        buf.lock().unwrap().clear();
+        render_with_term_width(&options, long_line, Some(10), &mut last_rendered);
+        assert!(
+            buf.lock().unwrap().is_empty(),
+            "unchanged, truncated content should not be redrawn"
+        );
+    }
+
+    #[test]
+    fn render_with_term_width_redraws_when_truncated_output_changes() {
+        let (output, buf) = captured_output();
+        let options = Options {
+            enable_ansi_escapes: true,
+            truncate_to_terminal_width: true,
+            output,
+            ..Default::default()
+        };
+        let mut last_rendered = String::new();
+
+        render_with_term_width(
+            &options,
+            "first line of status text".into(),
+            Some(10),
+            &mut last_rendered,
+        );
+        buf.lock().unwrap().clear();
+
+        render_with_term_width(
+            &options,
+            "second line of status text".into(),
+            Some(10),
+            &mut last_rendered,
+        );
+        assert!(!buf.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn suspend_clears_and_redraws_around_the_closure() {
+        let (output, buf) = captured_output();
+        let options = Options {
+            enable_ansi_escapes: true,
+            refresh_period: Duration::from_secs(3600),
+            output,
+            ..Default::default()
+        };
+        let status = StatusLine::with_options(42, options);
+        status.refresh();
+        buf.lock().unwrap().clear();
+
+        let mut ran = false;
+        status.suspend(|| ran = true);
+
+        assert!(ran, "suspend must still run the closure");
+        let written = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert!(
+            written.contains("\u{1b}[J"),
+            "suspend should clear before running the closure: {written:?}"
+        );
+        assert!(
+            written.contains('4') && written.contains('2'),
+            "suspend should redraw the status after the closure: {written:?}"
+        );
+    }
+
+    #[test]
+    fn println_writes_the_message_through_the_real_stdout_not_options_output() {
+        let (output, buf) = captured_output();
+        let options = Options {
+            enable_ansi_escapes: true,
+            refresh_period: Duration::from_secs(3600),
+            output,
+            ..Default::default()
+        };
+        let status = StatusLine::with_options(42, options);
+
+        // println always goes through the process's actual stdout, never through the
+        // configured `Options::output`: the `Writer` capture only ever sees the
+        // clear/redraw of the status itself, never the message text.
+        status.println("a distinctive log message");
+
+        let written = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert!(!written.contains("a distinctive log message"), "{written:?}");
+    }
+
+    #[test]
+    fn suspend_called_reentrantly_panics_instead_of_deadlocking() {
+        let (output, _buf) = captured_output();
+        let options = Options {
+            enable_ansi_escapes: true,
+            refresh_period: Duration::from_secs(3600),
+            output,
+            ..Default::default()
+        };
+        let status = StatusLine::with_options(7, options);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            status.suspend(|| status.refresh());
+        }));
+
+        assert!(
+            result.is_err(),
+            "a nested refresh/suspend/println from within suspend's closure must panic, not hang"
+        );
+        // The panic leaves the status's rendered-text mutex poisoned; the only thing that
+        // matters here is that we failed fast, not that `status` is still usable afterwards.
+        std::mem::forget(status);
+    }
+
+    fn board_options(output: Output) -> Options {
+        Options {
+            enable_ansi_escapes: false,
+            refresh_period: Duration::from_secs(3600),
+            output,
+            ..Default::default()
         }
     }
+
+    #[test]
+    fn status_board_joins_entries_in_order_and_skips_hidden_ones() {
+        let (output, buf) = captured_output();
+        let board = StatusBoard::with_options(board_options(output));
+        let first = board.add("first");
+        let second = board.add("second");
+
+        first.refresh();
+        let written = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert_eq!(written, "first\nsecond\n");
+
+        buf.lock().unwrap().clear();
+        second.set_visible(false);
+        let written = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            written, "first\n",
+            "a hidden entry must be excluded from the combined frame"
+        );
+    }
+
+    #[test]
+    fn dropping_status_board_handle_does_not_flash_while_a_line_is_still_alive() {
+        let (output, buf) = captured_output();
+        let options = Options {
+            enable_ansi_escapes: true,
+            ..board_options(output)
+        };
+        let board = StatusBoard::with_options(options);
+        let line = board.add("hello");
+        line.refresh();
+        buf.lock().unwrap().clear();
+
+        drop(board);
+        assert!(
+            buf.lock().unwrap().is_empty(),
+            "dropping the StatusBoard handle while a line is still alive must not erase the screen"
+        );
+
+        drop(line);
+        let written = String::from_utf8(buf.lock().unwrap().clone()).unwrap();
+        assert!(
+            written.contains("\u{1b}[J"),
+            "dropping the last remaining line must clear the board: {written:?}"
+        );
+    }
+
+    #[test]
+    fn dropping_an_empty_status_board_writes_nothing() {
+        let (output, buf) = captured_output();
+        let options = Options {
+            enable_ansi_escapes: true,
+            ..board_options(output)
+        };
+        let board = StatusBoard::with_options(options);
+
+        drop(board);
+
+        assert!(
+            buf.lock().unwrap().is_empty(),
+            "a board that never rendered anything shouldn't write a no-op EraseDown on drop"
+        );
+    }
 }